@@ -1,46 +1,90 @@
 use tokio;
 use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use surrealdb::{Datastore, Session};
-use surrealdb::sql::{Object, Value, Array, thing};
+use surrealdb::sql::{Object, Value, Array, Number, Strand, Thing, Id};
+use uuid::Uuid;
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
 
-	// Connect to Database by creating a new Store struct
+	// Connect to Database by creating a new Store struct.
+	// `Store::new()` is shorthand for `Store::builder().build()`, which also
+	// lets you pick the endpoint/namespace/database, e.g.:
+	// Store::builder().endpoint("file://data.db").namespace("app").database("prod").build().await?
     let store = Store::new().await?;
-    
-	// Create an object, return String id
-	let new_object_id = store.create().await?;
+
+	// Create an entity, return its RecordId
+	let new_object_id = store.create(Todo {
+		title: "Hello, world!".to_string(),
+		body: "Hello, SurrealDB with Rust!".to_string(),
+	}).await?;
     println!("{}", new_object_id);
 
-	// Get an object by id, return a surrealdb::Object
-    let object = store.get(&new_object_id).await?;
-    println!("Fetched Object: {}", object.to_string());
+	// Get an entity by id (a RecordId, or any "table:key" string), return a typed Todo
+    let todo: Todo = store.get(&new_object_id).await?;
+    println!("Fetched Object: {} - {}", todo.title, todo.body);
 
-	// Update an object by id, return String id
-	let new_object_id = store.create().await?;
+	// Update an entity by id, return its RecordId
+	let new_object_id = store.create(Todo {
+		title: "Hello, world!".to_string(),
+		body: "Hello, SurrealDB with Rust!".to_string(),
+	}).await?;
     println!("ID to be Updated: {}", new_object_id);
 
-	let updated_object_id = store.update(&new_object_id).await?;
+	let updated_object_id = store.update(&new_object_id, Todo {
+		title: "Updated!".to_string(),
+		body: "An Updated message!".to_string(),
+	}).await?;
 	println!("Updated ID: {}", updated_object_id);
-	
-	// Delete an object by id, return String id
-	let new_object_id = store.create().await?;
+
+	// Delete an entity by id, return its RecordId
+	let new_object_id = store.create(Todo {
+		title: "Hello, world!".to_string(),
+		body: "Hello, SurrealDB with Rust!".to_string(),
+	}).await?;
     println!("ID to be deleted: {}", new_object_id);
 
     let deleted_object_id = store.delete(&new_object_id).await?;
-    println!("Deleted Object ID: {}", deleted_object_id); 
+    println!("Deleted Object ID: {}", deleted_object_id);
 
-	// Get a list of items, returns Vec<surrealdb::Object>
-	let res = store.get_list().await?;
+	// Get a list of items, returns Vec<Todo>
+	let res: Vec<Todo> = store.list().await?;
 
-	// Collect response into a Vec<String>
-	let vals: Vec<String> = res.iter().map(|obj| {
-		obj.to_string()
-	}).collect();
+	for todo in res {
+		println!("Object in DB: {} - {}", todo.title, todo.body)
+	}
 
-	for obj in vals {
-		println!("Object in DB: {}", obj)
+	// Create two entities atomically, firing a callback only once both are committed
+	store.transaction(|tx| {
+		tx.create(Todo {
+			title: "First".to_string(),
+			body: "Created in a transaction".to_string(),
+		})?;
+		tx.create(Todo {
+			title: "Second".to_string(),
+			body: "Also created in the same transaction".to_string(),
+		})?;
+		tx.on_commit(|| println!("Transaction committed!"));
+		Ok(())
+	}).await?;
+
+	// Page through the todos titled "First", ordered by id descending
+	let page: Vec<Todo> = store
+		.list_where(
+			Query::new()
+				.filter("title", Op::Eq, "First")
+				.order_by("id", Dir::Desc)
+				.limit(10)
+				.start(0),
+		)
+		.await?;
+
+	for todo in page {
+		println!("Matched Object: {} - {}", todo.title, todo.body)
 	}
 
     Ok(())
@@ -70,38 +114,6 @@ impl TryFrom<W<Value>> for Array {
 	}
 }
 
-impl TryFrom<W<Value>> for i64 {
-	type Error = Error;
-	fn try_from(val: W<Value>) -> Result<i64, Error> {
-		match val.0 {
-			Value::Number(obj) => Ok(obj.as_int()),
-			_ => Err(Error::XValueNotOfType("i64")),
-		}
-	}
-}
-
-impl TryFrom<W<Value>> for bool {
-	type Error = Error;
-	fn try_from(val: W<Value>) -> Result<bool, Error> {
-		match val.0 {
-			Value::False => Ok(false),
-			Value::True => Ok(true),
-			_ => Err(Error::XValueNotOfType("bool")),
-		}
-	}
-}
-
-impl TryFrom<W<Value>> for String {
-	type Error = Error;
-	fn try_from(val: W<Value>) -> Result<String, Error> {
-		match val.0 {
-			Value::Strand(strand) => Ok(strand.as_string()),
-			Value::Thing(thing) => Ok(thing.to_string()),
-			_ => Err(Error::XValueNotOfType("String")),
-		}
-	}
-}
-
 // endregion:   ---- Generic Wrapper Struct for implementing From/TryFrom for type conversions SurrealDB Value <-> Object
 
 // region:      ---- Surreal DB Object implementations
@@ -152,33 +164,426 @@ impl<S> XTakeVal for S {
 
 impl XTakeImpl<String> for Object {
 	fn x_take_impl(&mut self, k: &str) -> Result<Option<String>, Error> {
-		let v = self.remove(k).map(|v| W(v).try_into());
-		match v {
+		match self.remove(k) {
 			None => Ok(None),
-			Some(Ok(val)) => Ok(Some(val)),
-			Some(Err(ex)) => Err(ex),
+			Some(v) => from_value(v).map(Some),
 		}
 	}
 }
 
 impl XTakeImpl<i64> for Object {
 	fn x_take_impl(&mut self, k: &str) -> Result<Option<i64>, Error> {
-		let v = self.remove(k).map(|v| W(v).try_into());
-		match v {
+		match self.remove(k) {
 			None => Ok(None),
-			Some(Ok(val)) => Ok(Some(val)),
-			Some(Err(ex)) => Err(ex),
+			Some(v) => from_value(v).map(Some),
 		}
 	}
 }
 
 impl XTakeImpl<bool> for Object {
 	fn x_take_impl(&mut self, k: &str) -> Result<Option<bool>, Error> {
-		Ok(self.remove(k).map(|v| v.is_true()))
+		match self.remove(k) {
+			None => Ok(None),
+			Some(v) => from_value(v).map(Some),
+		}
 	}
 }
 // endregion:   ---- Surreal DB Object implementations
 
+// region:      ---- Serde <-> Value bridge
+
+/// Deserialize a whole SurrealDB `Value` into any `Deserialize` type, instead
+/// of pulling it apart field by field with `XTake`.
+pub fn from_value<T: DeserializeOwned>(v: Value) -> Result<T, Error> {
+	let json = value_to_json(v)?;
+	serde_json::from_value(json).map_err(Error::SerdeError)
+}
+
+/// Serialize any `Serialize` type into a SurrealDB `Value`, suitable for a
+/// `SET`/`MERGE` payload's bound vars.
+pub fn to_value<T: Serialize>(t: &T) -> Result<Value, Error> {
+	let json = serde_json::to_value(t).map_err(Error::SerdeError)?;
+	json_to_value(json)
+}
+
+fn value_to_json(v: Value) -> Result<serde_json::Value, Error> {
+	let json = match v {
+		Value::None | Value::Null => serde_json::Value::Null,
+		Value::True => serde_json::Value::Bool(true),
+		Value::False => serde_json::Value::Bool(false),
+		Value::Number(n) if n.is_int() => serde_json::Value::from(n.as_int()),
+		Value::Number(n) => serde_json::Value::from(n.as_float()),
+		Value::Strand(s) => serde_json::Value::String(s.as_string()),
+		Value::Thing(thing) => serde_json::Value::String(thing.to_string()),
+		Value::Datetime(d) => serde_json::Value::String(d.to_string()),
+		Value::Duration(d) => serde_json::Value::String(d.to_string()),
+		Value::Uuid(u) => serde_json::Value::String(u.to_string()),
+		Value::Array(arr) => {
+			let items: Result<Vec<_>, Error> = arr.into_iter().map(value_to_json).collect();
+			serde_json::Value::Array(items?)
+		}
+		Value::Object(obj) => {
+			let mut map = serde_json::Map::new();
+			for (k, v) in obj.into_iter() {
+				map.insert(k, value_to_json(v)?);
+			}
+			serde_json::Value::Object(map)
+		}
+		_ => return Err(Error::XValueNotOfType("json-compatible value")),
+	};
+
+	Ok(json)
+}
+
+fn json_to_value(json: serde_json::Value) -> Result<Value, Error> {
+	let value = match json {
+		serde_json::Value::Null => Value::Null,
+		serde_json::Value::Bool(true) => Value::True,
+		serde_json::Value::Bool(false) => Value::False,
+		serde_json::Value::Number(n) if n.is_i64() => Value::Number(Number::from(n.as_i64().unwrap())),
+		serde_json::Value::Number(n) => Value::Number(Number::from(n.as_f64().unwrap_or_default())),
+		serde_json::Value::String(s) => Value::Strand(Strand::from(s)),
+		serde_json::Value::Array(arr) => {
+			let items: Result<Vec<_>, Error> = arr.into_iter().map(json_to_value).collect();
+			Value::Array(Array::from(items?))
+		}
+		serde_json::Value::Object(map) => {
+			let mut obj = Object::default();
+			for (k, v) in map.into_iter() {
+				obj.insert(k, json_to_value(v)?);
+			}
+			Value::Object(obj)
+		}
+	};
+
+	Ok(value)
+}
+
+// endregion:   ---- Serde <-> Value bridge
+
+// region:      ---- Query
+
+/// Comparison operator for a `Query::filter` clause.
+pub enum Op {
+	Eq,
+	Neq,
+	Gt,
+	Gte,
+	Lt,
+	Lte,
+}
+
+impl Op {
+	fn as_sql(&self) -> &'static str {
+		match self {
+			Op::Eq => "=",
+			Op::Neq => "!=",
+			Op::Gt => ">",
+			Op::Gte => ">=",
+			Op::Lt => "<",
+			Op::Lte => "<=",
+		}
+	}
+}
+
+/// Sort direction for a `Query::order_by` clause.
+pub enum Dir {
+	Asc,
+	Desc,
+}
+
+impl Dir {
+	fn as_sql(&self) -> &'static str {
+		match self {
+			Dir::Asc => "ASC",
+			Dir::Desc => "DESC",
+		}
+	}
+}
+
+/// Builds a `SELECT * FROM <table> WHERE ... ORDER BY ... LIMIT ... START ...`
+/// statement for `Store::list_where`. Every filter/limit/start value is bound
+/// through `vars` rather than interpolated into the statement, so those values
+/// can never change the shape of the query. The table name and the filter and
+/// `order_by` field names are written into the statement as given.
+#[derive(Default)]
+pub struct Query {
+	filters: Vec<(String, Op, Value)>,
+	order_by: Option<(String, Dir)>,
+	limit: Option<i64>,
+	start: Option<i64>,
+}
+
+impl Query {
+	pub fn new() -> Self {
+		Query::default()
+	}
+
+	pub fn filter<T: Into<Value>>(mut self, field: &str, op: Op, value: T) -> Self {
+		self.filters.push((field.to_string(), op, value.into()));
+		self
+	}
+
+	pub fn order_by(mut self, field: &str, dir: Dir) -> Self {
+		self.order_by = Some((field.to_string(), dir));
+		self
+	}
+
+	pub fn limit(mut self, limit: i64) -> Self {
+		self.limit = Some(limit);
+		self
+	}
+
+	pub fn start(mut self, start: i64) -> Self {
+		self.start = Some(start);
+		self
+	}
+
+	fn compile(self, table: &str) -> (String, BTreeMap<String, Value>) {
+		let mut sql = format!("SELECT * FROM {table}");
+		let mut vars = BTreeMap::new();
+
+		if !self.filters.is_empty() {
+			let clauses: Vec<String> = self
+				.filters
+				.into_iter()
+				.enumerate()
+				.map(|(i, (field, op, value))| {
+					let var_key = format!("f{i}");
+					let clause = format!("{field} {} ${var_key}", op.as_sql());
+					vars.insert(var_key, value);
+					clause
+				})
+				.collect();
+
+			sql.push_str(" WHERE ");
+			sql.push_str(&clauses.join(" AND "));
+		}
+
+		if let Some((field, dir)) = self.order_by {
+			sql.push_str(&format!(" ORDER BY {field} {}", dir.as_sql()));
+		}
+
+		if let Some(limit) = self.limit {
+			sql.push_str(" LIMIT $limit");
+			vars.insert("limit".to_string(), limit.into());
+		}
+
+		if let Some(start) = self.start {
+			sql.push_str(" START $start");
+			vars.insert("start".to_string(), start.into());
+		}
+
+		(sql, vars)
+	}
+}
+
+// endregion:   ---- Query
+
+// region:      ---- RecordId
+
+/// The key half of a `table:key` record id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Key {
+	String(String),
+	Int(i64),
+	Uuid(Uuid),
+}
+
+impl fmt::Display for Key {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Key::String(s) if key_string_needs_escaping(s) => {
+				write!(f, "⟨{}⟩", s.replace('\\', "\\\\").replace('⟩', "\\⟩"))
+			}
+			Key::String(s) => write!(f, "{s}"),
+			Key::Int(i) => write!(f, "{i}"),
+			Key::Uuid(u) => write!(f, "{u}"),
+		}
+	}
+}
+
+/// A bare (unescaped) key would be ambiguous with another `Key` variant, or
+/// isn't a plain identifier, so `Display` must wrap it in `⟨...⟩` and
+/// `FromStr` must only unwrap (never re-sniff the type of) a wrapped key.
+fn key_string_needs_escaping(s: &str) -> bool {
+	s.is_empty()
+		|| s.parse::<i64>().is_ok()
+		|| Uuid::parse_str(s).is_ok()
+		|| !s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A structured SurrealDB record id (`table:key`), replacing raw `&str` ids
+/// that silently fail on malformed input and lose the table/key split.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordId {
+	pub table: String,
+	pub key: Key,
+}
+
+impl fmt::Display for RecordId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}:{}", self.table, self.key)
+	}
+}
+
+impl FromStr for RecordId {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Error> {
+		let (table, key) = s.split_once(':').ok_or_else(|| Error::InvalidRecordId(s.to_string()))?;
+
+		if table.is_empty() || key.is_empty() {
+			return Err(Error::InvalidRecordId(s.to_string()));
+		}
+
+		Ok(RecordId { table: table.to_string(), key: parse_key(key) })
+	}
+}
+
+/// Mirrors `Key`'s `Display`: an `⟨...⟩`-wrapped key is always a literal
+/// string (unescaped verbatim, never re-sniffed as a number/UUID), while a
+/// bare key is classified by shape exactly like `key_string_needs_escaping`
+/// would have escaped it.
+fn parse_key(key: &str) -> Key {
+	if let Some(inner) = key.strip_prefix('⟨').and_then(|s| s.strip_suffix('⟩')) {
+		let unescaped = inner.replace("\\⟩", "⟩").replace("\\\\", "\\");
+		return Key::String(unescaped);
+	}
+
+	if let Ok(i) = key.parse::<i64>() {
+		Key::Int(i)
+	} else if let Ok(u) = Uuid::parse_str(key) {
+		Key::Uuid(u)
+	} else {
+		Key::String(key.to_string())
+	}
+}
+
+impl TryFrom<&str> for RecordId {
+	type Error = Error;
+
+	fn try_from(s: &str) -> Result<Self, Error> {
+		s.parse()
+	}
+}
+
+impl From<RecordId> for Value {
+	fn from(id: RecordId) -> Value {
+		// Build the `Thing` directly from `table`/`key` instead of
+		// round-tripping through `thing(&id.to_string())`: `table`/`key` can
+		// contain characters (spaces, extra colons, ...) that aren't valid
+		// bare identifiers in SurrealQL's record-id grammar, which would make
+		// re-parsing the rendered string fail even though the `RecordId`
+		// itself is perfectly well-formed.
+		let record_id = match id.key {
+			Key::String(key) => Thing { tb: id.table, id: Id::String(key) },
+			Key::Int(key) => Thing { tb: id.table, id: Id::Number(key) },
+			Key::Uuid(key) => Thing { tb: id.table, id: Id::String(key.to_string()) },
+		};
+
+		Value::Thing(record_id)
+	}
+}
+
+impl From<Thing> for RecordId {
+	fn from(thing: Thing) -> Self {
+		// Classify the key from the `Id` SurrealDB actually returned, not by
+		// re-parsing its rendered string: a string key that happens to look
+		// like a number must stay a `Key::String`, never get reclassified as
+		// `Key::Int` the way a naive re-parse of `thing.to_string()` would.
+		// `Key::Uuid` is wire-indistinguishable from `Key::String` (SurrealDB
+		// has no dedicated id kind for it here), so it can only be produced
+		// by constructing/parsing a `RecordId` directly, never by reading one
+		// back from the database.
+		let key = match thing.id {
+			Id::Number(n) => Key::Int(n),
+			Id::String(s) => Key::String(s),
+			other => Key::String(other.to_string()),
+		};
+
+		RecordId { table: thing.tb, key }
+	}
+}
+
+/// Accepts anything that can become a `RecordId`: a borrowed/owned id, or a
+/// `"table:key"` string. Parsing can fail (unlike `std::convert::Into`), so
+/// this is a dedicated trait rather than `Into<RecordId>`.
+pub trait IntoRecordId {
+	fn into_record_id(self) -> Result<RecordId, Error>;
+}
+
+impl IntoRecordId for RecordId {
+	fn into_record_id(self) -> Result<RecordId, Error> {
+		Ok(self)
+	}
+}
+
+impl IntoRecordId for &RecordId {
+	fn into_record_id(self) -> Result<RecordId, Error> {
+		Ok(self.clone())
+	}
+}
+
+impl IntoRecordId for &str {
+	fn into_record_id(self) -> Result<RecordId, Error> {
+		self.parse()
+	}
+}
+
+impl IntoRecordId for String {
+	fn into_record_id(self) -> Result<RecordId, Error> {
+		self.parse()
+	}
+}
+
+// endregion:   ---- RecordId
+
+// region:      ---- Entity
+
+/// Implemented by every type that can be stored through a [`Store`].
+///
+/// `table()` binds the Rust type to a SurrealDB table so the generic CRUD
+/// methods on `Store` never need to know which table they are hitting.
+pub trait Entity: Sized {
+	/// The SurrealDB table this entity is stored in.
+	fn table() -> &'static str;
+
+	/// Consume `self` into the `Object` that will back a `CREATE`/`UPDATE` statement.
+	fn into_object(self) -> Object;
+
+	/// Rebuild `Self` from a row returned by SurrealDB.
+	fn from_object(obj: Object) -> Result<Self, Error>;
+}
+
+/// Example entity backing the `todo` table used by the demo in `main`.
+pub struct Todo {
+	pub title: String,
+	pub body: String,
+}
+
+impl Entity for Todo {
+	fn table() -> &'static str {
+		"todo"
+	}
+
+	fn into_object(self) -> Object {
+		let mut obj = Object::default();
+		obj.insert("title".to_string(), self.title.into());
+		obj.insert("body".to_string(), self.body.into());
+		obj
+	}
+
+	fn from_object(mut obj: Object) -> Result<Self, Error> {
+		Ok(Todo {
+			title: obj.x_take_val::<String>("title")?,
+			body: obj.x_take_val::<String>("body")?,
+		})
+	}
+}
+
+// endregion:   ---- Entity
+
 // region:      ---- Error type enumerator
 
 // enumerate errors to allow use of the ? operator
@@ -201,10 +606,169 @@ pub enum Error {
 
 	#[error(transparent)]
 	IOError(#[from] std::io::Error),
+
+	#[error("Transaction error: {0:?}")]
+	TxError(TxError),
+
+	#[error(transparent)]
+	SerdeError(#[from] serde_json::Error),
+
+	#[error("Invalid store config: {0}")]
+	ConfigInvalid(String),
+
+	#[error("Invalid record id: '{0}'")]
+	InvalidRecordId(String),
 }
 
 // endregion:   ---- Error type enumerator
 
+// region:      ---- Transaction
+
+/// Outcome a transaction closure can return to abort the in-flight transaction.
+#[derive(Debug)]
+pub enum TxError {
+	/// Roll back everything buffered so far and skip all `on_commit` callbacks.
+	Abort,
+}
+
+/// Buffers statements and on-commit callbacks for a single `Store::transaction` call.
+///
+/// Nothing is sent to SurrealDB while the closure runs; `Store::transaction`
+/// only issues `BEGIN TRANSACTION ... COMMIT TRANSACTION` (or `CANCEL
+/// TRANSACTION` on abort) once the closure returns.
+pub struct Transaction {
+	statements: Vec<String>,
+	vars: BTreeMap<String, Value>,
+	on_commit: Vec<Box<dyn FnOnce()>>,
+}
+
+impl Transaction {
+	fn new() -> Self {
+		Transaction { statements: Vec::new(), vars: BTreeMap::new(), on_commit: Vec::new() }
+	}
+
+	/// Unique prefix for this statement's bound vars, so field names don't
+	/// collide across statements sharing the same transaction.
+	fn next_prefix(&self) -> String {
+		format!("tx{}", self.statements.len())
+	}
+
+	pub fn create<E: Entity>(&mut self, e: E) -> Result<(), Error> {
+		let prefix = self.next_prefix();
+		let (assignments, vars) = Store::object_to_assignments_prefixed(e.into_object(), &prefix);
+
+		self.statements.push(format!("CREATE {} SET {assignments}", E::table()));
+		self.vars.extend(vars);
+
+		Ok(())
+	}
+
+	pub fn update<E: Entity>(&mut self, id: impl IntoRecordId, e: E) -> Result<(), Error> {
+		let prefix = self.next_prefix();
+		let (assignments, mut vars) = Store::object_to_assignments_prefixed(e.into_object(), &prefix);
+		let th_key = format!("{prefix}_th");
+
+		self.statements.push(format!("UPDATE ${th_key} MERGE {{ {assignments} }}"));
+		vars.insert(th_key, id.into_record_id()?.into());
+		self.vars.extend(vars);
+
+		Ok(())
+	}
+
+	pub fn delete(&mut self, id: impl IntoRecordId) -> Result<(), Error> {
+		let prefix = self.next_prefix();
+		let th_key = format!("{prefix}_th");
+
+		self.statements.push(format!("DELETE ${th_key}"));
+		self.vars.insert(th_key, id.into_record_id()?.into());
+
+		Ok(())
+	}
+
+	/// Register a closure to run after the transaction commits successfully.
+	/// Never runs if the transaction aborts or fails to commit, nor when the
+	/// transaction holds no statements (nothing is sent, so nothing commits).
+	pub fn on_commit<F: FnOnce() + 'static>(&mut self, f: F) {
+		self.on_commit.push(Box::new(f));
+	}
+}
+
+// endregion:   ---- Transaction
+
+// region:      ---- StoreConfig
+
+/// Configuration for a `Store`: where the datastore lives (`memory`,
+/// `file://path`, `tikv://...`, `rocksdb://...`) and which namespace/database
+/// to use inside it. Build one with `Store::builder()`.
+///
+/// Note: no credentials can be configured. Every `Store` opens its session
+/// with `Session::for_db` on the configured namespace/database; root or scope
+/// sign-in is not supported.
+pub struct StoreConfig {
+	endpoint: String,
+	namespace: String,
+	database: String,
+}
+
+impl Default for StoreConfig {
+	fn default() -> Self {
+		StoreConfig {
+			endpoint: "memory".to_string(),
+			namespace: "test".to_string(),
+			database: "test".to_string(),
+		}
+	}
+}
+
+/// Builder for `StoreConfig`. Validated on `build()`, not on each setter call.
+#[derive(Default)]
+pub struct StoreConfigBuilder {
+	config: StoreConfig,
+}
+
+impl StoreConfigBuilder {
+	/// Datastore endpoint, e.g. `memory`, `file://data.db`, `tikv://127.0.0.1:2379`.
+	pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+		self.config.endpoint = endpoint.into();
+		self
+	}
+
+	pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+		self.config.namespace = namespace.into();
+		self
+	}
+
+	pub fn database(mut self, database: impl Into<String>) -> Self {
+		self.config.database = database.into();
+		self
+	}
+
+	fn validate(&self) -> Result<(), Error> {
+		if self.config.endpoint.trim().is_empty() {
+			return Err(Error::ConfigInvalid("endpoint must not be empty".to_string()));
+		}
+		if self.config.namespace.trim().is_empty() {
+			return Err(Error::ConfigInvalid("namespace must not be empty".to_string()));
+		}
+		if self.config.database.trim().is_empty() {
+			return Err(Error::ConfigInvalid("database must not be empty".to_string()));
+		}
+
+		Ok(())
+	}
+
+	pub async fn build(self) -> Result<Store, Error> {
+		self.validate()?;
+
+		let ds = Datastore::new(&self.config.endpoint).await?;
+		let ses = Session::for_db(&self.config.namespace, &self.config.database);
+
+		Ok(Store { ds, ses })
+	}
+}
+
+// endregion:   ---- StoreConfig
+
 // region:      ---- Store
 
 struct Store {
@@ -213,80 +777,198 @@ struct Store {
 }
 
 impl Store {
+	/// Start building a `Store` with a custom endpoint, namespace, or database.
+	pub fn builder() -> StoreConfigBuilder {
+		StoreConfigBuilder::default()
+	}
+
     pub async fn new() -> Result<Self, Error> {
-        let ds = Datastore::new("memory").await?;
-		
-		let ses = Session::for_db("test", "test");
-		
-		Ok(Store { ds, ses })
+		Self::builder().build().await
     }
 
-    pub async fn get_list(&self) -> Result<Vec<Object>, Error> {
-        let sql = "SELECT * FROM todo";
+	/// Turn an entity `Object` into a `field = $f_field, ...` assignment list
+	/// plus the bound vars backing each `$f_field`, so values are never
+	/// string-interpolated into the statement.
+	/// Field var keys always carry the `f_` marker, so they can never collide
+	/// with a control var like the `th` record-id binding `update`/`delete`
+	/// add on top — no matter what an `Entity` happens to call its fields.
+	fn object_to_assignments(obj: Object) -> (String, BTreeMap<String, Value>) {
+		let mut vars = BTreeMap::new();
+		let assignments: Vec<String> = obj
+			.into_iter()
+			.map(|(k, v)| {
+				let var_key = format!("f_{k}");
+				let assignment = format!("{k} = ${var_key}");
+				vars.insert(var_key, v);
+				assignment
+			})
+			.collect();
+
+		(assignments.join(", "), vars)
+	}
+
+	/// Same as `object_to_assignments`, but additionally namespaces every
+	/// bound var with `prefix` so several statements can share one `vars` map
+	/// without clobbering each other's fields. The `f_` marker still keeps
+	/// field vars distinct from the `{prefix}_th` control var `Transaction`
+	/// adds alongside them.
+	fn object_to_assignments_prefixed(obj: Object, prefix: &str) -> (String, BTreeMap<String, Value>) {
+		let mut vars = BTreeMap::new();
+		let assignments: Vec<String> = obj
+			.into_iter()
+			.map(|(k, v)| {
+				let var_key = format!("{prefix}_f_{k}");
+				let assignment = format!("{k} = ${var_key}");
+				vars.insert(var_key, v);
+				assignment
+			})
+			.collect();
+
+		(assignments.join(", "), vars)
+	}
+
+	/// Run `f` to build up a set of statements and on-commit callbacks, then
+	/// commit them atomically. Returning `Err(Error::TxError(TxError::Abort))`
+	/// from `f` cancels the transaction and skips every `on_commit` callback.
+	pub async fn transaction<F>(&self, f: F) -> Result<(), Error>
+	where
+		F: FnOnce(&mut Transaction) -> Result<(), Error>,
+	{
+		let mut tx = Transaction::new();
+
+		match f(&mut tx) {
+			Ok(()) => {
+				if tx.statements.is_empty() {
+					return Ok(());
+				}
+
+				let mut sql = String::from("BEGIN TRANSACTION;\n");
+				for statement in &tx.statements {
+					sql.push_str(statement);
+					sql.push_str(";\n");
+				}
+				sql.push_str("COMMIT TRANSACTION;");
+
+				let res = self.ds.execute(&sql, &self.ses, Some(tx.vars), false).await?;
+				for r in res {
+					r.result?;
+				}
+
+				for on_commit in tx.on_commit {
+					on_commit();
+				}
+
+				Ok(())
+			}
+			Err(err @ Error::TxError(TxError::Abort)) => {
+				self.ds.execute("BEGIN TRANSACTION; CANCEL TRANSACTION;", &self.ses, None, false).await?;
+				Err(err)
+			}
+			Err(err) => Err(err),
+		}
+	}
+
+	pub async fn list<E: Entity>(&self) -> Result<Vec<E>, Error> {
+        let sql = format!("SELECT * FROM {}", E::table());
+
+        let res = self.ds.execute(&sql, &self.ses, None, true).await?;
 
-        let res = self.ds.execute(sql, &self.ses, None, true).await?;
-        
 		let first_res = res.into_iter().next().expect("Did not get a response");
 
 		let array: Array = W(first_res.result?).try_into()?;
 
-		array.into_iter().map(|value| W(value).try_into()).collect()
+		array
+			.into_iter()
+			.map(|value| E::from_object(W(value).try_into()?))
+			.collect()
     }
 
-    pub async fn get(&self, uid: &str) -> Result<Object, Error> {
-        let sql = "SELECT * FROM todo WHERE id = $id";
-        
+	/// Same as `list`, but filtered/ordered/paginated per the given `Query`.
+	pub async fn list_where<E: Entity>(&self, q: Query) -> Result<Vec<E>, Error> {
+		let (sql, vars) = q.compile(E::table());
+
+		let res = self.ds.execute(&sql, &self.ses, Some(vars), true).await?;
+
+		let first_res = res.into_iter().next().expect("Did not get a response");
+
+		let array: Array = W(first_res.result?).try_into()?;
+
+		array
+			.into_iter()
+			.map(|value| E::from_object(W(value).try_into()?))
+			.collect()
+	}
+
+    pub async fn get<E: Entity>(&self, id: impl IntoRecordId) -> Result<E, Error> {
+        let sql = format!("SELECT * FROM {} WHERE id = $id", E::table());
+
 		let vars: BTreeMap<String, Value> = BTreeMap::from([(
-            "id".into(), thing(uid)?.into()
+            "id".into(), id.into_record_id()?.into()
         )]);
 
-        let res = self.ds.execute(sql, &self.ses, Some(vars), true).await?;
-        
+        let res = self.ds.execute(&sql, &self.ses, Some(vars), true).await?;
+
 		let first_res = res.into_iter().next().expect("Did not get a response!");
-        
-		W(first_res.result?.first()).try_into()
+
+		let obj: Object = W(first_res.result?.first()).try_into()?;
+
+		E::from_object(obj)
     }
 
-    pub async fn create(&self) -> Result<String, Error> {
-        let sql = "CREATE todo SET title = 'Hello, world!', body = 'Hello, SurrealDB with Rust!'";
-        
-		let res = self.ds.execute(sql, &self.ses, None, false).await?;
-		
+	/// Pull the `id` field back out of a returned row as a `RecordId`, using
+	/// the `Thing` SurrealDB actually sent rather than re-deriving the key's
+	/// type from a re-rendered string (which would lose, e.g., whether a
+	/// numeric-looking key was a string or a number to begin with).
+	fn take_record_id(obj: &mut Object) -> Result<RecordId, Error> {
+		match obj.remove("id") {
+			Some(Value::Thing(thing)) => Ok(RecordId::from(thing)),
+			Some(_) => Err(Error::XValueNotOfType("Thing")),
+			None => Err(Error::XPropertyNotFound("id".to_string())),
+		}
+	}
+
+    pub async fn create<E: Entity>(&self, e: E) -> Result<RecordId, Error> {
+		let (assignments, vars) = Self::object_to_assignments(e.into_object());
+
+        let sql = format!("CREATE {} SET {assignments}", E::table());
+
+		let res = self.ds.execute(&sql, &self.ses, Some(vars), false).await?;
+
 		let first_val = res.into_iter().next().map(|r| r.result).expect("id not returned")?;
-        
+
 		if let Value::Object(mut val) = first_val.first() {
-            let id = val.x_take_val::<String>("id")?;
-            Ok(id)
+            Self::take_record_id(&mut val)
         }else {
 			Err(Error::StoreFailToCreate(format!("exec_create, nothing returned.")))
 		}
     }
-    
-	pub async fn update(&self, tid: &str) -> Result<String, Error> {
-		let sql = "UPDATE $th MERGE { body: 'An Updated message!', title: 'Updated!' } RETURN id";
-		
-		let vars: BTreeMap<String, Value> = BTreeMap::from([(
-            "th".into(), thing(tid)?.into(),
-			
-        )]);
-        
-		let res = self.ds.execute(sql, &self.ses, Some(vars), true).await?;
-		
+
+	pub async fn update<E: Entity>(&self, id: impl IntoRecordId, e: E) -> Result<RecordId, Error> {
+		let id = id.into_record_id()?;
+		let (assignments, mut vars) = Self::object_to_assignments(e.into_object());
+
+		let sql = format!("UPDATE $th MERGE {{ {assignments} }} RETURN id");
+
+		vars.insert("th".into(), id.clone().into());
+
+		let res = self.ds.execute(&sql, &self.ses, Some(vars), true).await?;
+
 		let first_res = res.into_iter().next().expect("id not returned");
-        
+
 		let result = first_res.result?;
 
 		if let Value::Object(mut val) = result.first() {
-			val.x_take_val::<String>("id")
+			Self::take_record_id(&mut val)
 		} else {
-			Err(Error::StoreFailToCreate(format!("exec_merge {tid}, nothing returned.")))
+			Err(Error::StoreFailToCreate(format!("exec_merge {id}, nothing returned.")))
 		}
     }
-    
-	pub async fn delete(&self, tid: &str) -> Result<String, Error> {
+
+	pub async fn delete(&self, id: impl IntoRecordId) -> Result<RecordId, Error> {
+		let id = id.into_record_id()?;
 		let sql = "DELETE $th";
 
-		let vars = BTreeMap::from([("th".into(), thing(tid)?.into())]);
+		let vars = BTreeMap::from([("th".into(), id.clone().into())]);
 
 		let ress = self.ds.execute(sql, &self.ses, Some(vars), false).await?;
 
@@ -294,8 +976,8 @@ impl Store {
 
 		first_res.result?;
 
-		Ok(tid.to_string())
+		Ok(id)
     }
 }
 
-// endregion:   ---- Store
\ No newline at end of file
+// endregion:   ---- Store